@@ -1,6 +1,8 @@
 use std::default;
-use std::process::Command;
+use std::io::Write;
+use std::process::{Command, Stdio};
 use std::path::PathBuf;
+use std::thread;
 use std::vec::Vec;
 
 use errors::*;
@@ -14,6 +16,7 @@ pub struct Assert {
     expect_success: Option<bool>,
     expect_exit_code: Option<i32>,
     expect_output: Vec<OutputAssertion>,
+    stdin: Option<Vec<u8>>,
 }
 
 impl default::Default for Assert {
@@ -28,6 +31,7 @@ impl default::Default for Assert {
             expect_success: Some(true),
             expect_exit_code: None,
             expect_output: vec![],
+            stdin: None,
         }
     }
 }
@@ -105,6 +109,23 @@ impl Assert {
         self
     }
 
+    /// Feed `input` to the command's standard input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate assert_cli;
+    ///
+    /// assert_cli::Assert::command(&["cat"])
+    ///     .stdin("42")
+    ///     .stdout().is("42")
+    ///     .unwrap();
+    /// ```
+    pub fn stdin<S: Into<Vec<u8>>>(mut self, input: S) -> Self {
+        self.stdin = Some(input.into());
+        self
+    }
+
     /// Small helper to make chains more readable.
     ///
     /// # Examples
@@ -237,7 +258,24 @@ impl Assert {
             Some(ref dir) => command.current_dir(dir),
             None => command,
         };
-        let output = command.output()?;
+        let output = match self.stdin {
+            Some(ref input) => {
+                let mut child = command
+                    .stdin(Stdio::piped())
+                    .stdout(Stdio::piped())
+                    .stderr(Stdio::piped())
+                    .spawn()?;
+                let mut stdin = child.stdin.take().expect("Child's stdin was piped");
+                let input = input.clone();
+                thread::spawn(move || {
+                    // Ignore the error: the child may exit (or close its
+                    // stdin) before consuming all of the input, e.g. `head`.
+                    let _ = stdin.write_all(&input);
+                });
+                child.wait_with_output()?
+            }
+            None => command.output()?,
+        };
 
         if let Some(expect_success) = self.expect_success {
             if expect_success != output.status.success() {